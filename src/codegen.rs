@@ -0,0 +1,98 @@
+use crate::interpreter::TableStruct;
+use crate::parser::ColumnType;
+
+/// Emits compilable Rust source for a `TableStruct`, mapping SQL column types to their
+/// idiomatic Rust equivalents and wrapping nullable columns in `Option<T>`.
+pub fn emit(table: &TableStruct) -> String {
+    let struct_name = to_pascal_case(&table.name);
+    let mut source = format!("#[derive(Debug, Clone)]\npub struct {struct_name} {{\n");
+    for field in &table.fields {
+        let rust_type = rust_type(&field.column_type);
+        let field_type = if field.nullable {
+            format!("Option<{rust_type}>")
+        } else {
+            rust_type
+        };
+        source.push_str(&format!(
+            "    pub {}: {},\n",
+            to_snake_case(&field.name),
+            field_type
+        ));
+    }
+    source.push_str("}\n");
+    source
+}
+
+fn rust_type(column_type: &ColumnType) -> String {
+    match column_type {
+        ColumnType::Int => "i64".into(),
+        ColumnType::Varchar(_) => "String".into(),
+        ColumnType::Char(_) => "String".into(),
+        ColumnType::Date => "chrono::NaiveDate".into(),
+        ColumnType::Json => "serde_json::Value".into(),
+        ColumnType::Decimal { .. } => "f64".into(),
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::emit;
+    use crate::interpreter::{Field, TableStruct};
+    use crate::parser::ColumnType;
+
+    #[test]
+    fn test_emit() {
+        let table = TableStruct {
+            name: "user_account".into(),
+            fields: vec![
+                Field {
+                    name: "id".into(),
+                    column_type: ColumnType::Int,
+                    nullable: false,
+                },
+                Field {
+                    name: "display_name".into(),
+                    column_type: ColumnType::Varchar(Some(255)),
+                    nullable: true,
+                },
+                Field {
+                    name: "created_at".into(),
+                    column_type: ColumnType::Date,
+                    nullable: false,
+                },
+            ],
+        };
+        assert_eq!(
+            emit(&table),
+            "#[derive(Debug, Clone)]\npub struct UserAccount {\n    pub id: i64,\n    pub display_name: Option<String>,\n    pub created_at: chrono::NaiveDate,\n}\n"
+        );
+    }
+}
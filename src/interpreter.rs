@@ -1,16 +1,17 @@
 
-use crate::parser::Ast;
+use crate::parser::{Ast, ColumnType};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Field {
-    name: String,
-    field_type: String,
+    pub(crate) name: String,
+    pub(crate) column_type: ColumnType,
+    pub(crate) nullable: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct TableStruct {
-    name: String,
-    fields: Vec<Field>,
+    pub(crate) name: String,
+    pub(crate) fields: Vec<Field>,
 }
 
 struct TableStructBuilder {
@@ -47,7 +48,14 @@ impl TableStructBuilder {
 pub struct Interpreter {}
 
 impl Interpreter {
-    pub fn run(&self, ast: Ast) -> TableStruct {
+    pub fn run(&self, statements: Vec<Ast>) -> Vec<TableStruct> {
+        statements
+            .into_iter()
+            .map(|ast| self.run_one(ast))
+            .collect()
+    }
+
+    fn run_one(&self, ast: Ast) -> TableStruct {
         let mut builder = TableStructBuilder::new();
         self.eval(ast, &mut builder);
         builder.build()
@@ -55,11 +63,13 @@ impl Interpreter {
 
     fn eval(&self, ast: Ast, builder: &mut TableStructBuilder) {
         match ast {
-            Ast::Expr { table_name, expr1 } => {
-                builder.name(table_name);
+            Ast::Expr {
+                table_name, expr1, ..
+            } => {
+                builder.name(table_name.to_string());
                 self.eval(*expr1, builder)
             }
-            Ast::Expr1 { expr2, expr1 } => {
+            Ast::Expr1 { expr2, expr1, .. } => {
                 self.eval(*expr2, builder);
                 if let Some(expr1) = expr1 {
                     self.eval(*expr1, builder)
@@ -68,13 +78,18 @@ impl Interpreter {
             Ast::Expr2 {
                 name,
                 column_type,
-                null: _,
+                null,
+                ..
             } => {
                 builder.field(Field {
-                    name,
-                    field_type: format!("{:?}", column_type),
+                    name: name.to_string(),
+                    column_type,
+                    nullable: null,
                 });
             }
+            Ast::Poison { .. } => {
+                // The parser already recorded why this column failed to parse; skip it.
+            }
         }
     }
 }
@@ -90,27 +105,31 @@ mod tests {
     fn test_run() {
         let interpreter = Interpreter {};
         let ast = Expr {
-            table_name: "table_name".into(),
+            table_name: "table_name",
             expr1: Box::new(Expr1 {
                 expr2: Box::new(Expr2 {
-                    name: "name".into(),
+                    name: "name",
                     column_type: ColumnType::Int,
                     null: true,
+                    span: (0, 0),
                 }),
                 expr1: None,
+                span: (0, 0),
             }),
+            span: (0, 0),
         };
-        let result = interpreter.run(ast);
+        let result = interpreter.run(vec![ast]);
         let fields = vec![Field {
             name: "name".into(),
-            field_type: "Int".into(),
+            column_type: ColumnType::Int,
+            nullable: true,
         }];
         assert_eq!(
             result,
-            TableStruct {
+            vec![TableStruct {
                 name: "table_name".into(),
                 fields
-            }
+            }]
         )
     }
 }
@@ -1,38 +1,49 @@
 use crate::lexer::LexError::InvalidError;
 use crate::lexer::LexicalToken::{
-    Comma, CreateTable, Date, Int, Json, LParen, NotNull, RParen, Semicolon, Text, Varchar,
+    Char, Comma, CreateTable, Date, Decimal, Int, Json, LParen, NotNull, RParen, Semicolon, Text,
+    Varchar,
 };
 use regex::Regex;
 use std::str::from_utf8;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
-pub enum LexicalToken {
+pub enum LexicalToken<'a> {
     CreateTable,
     Int,
     Varchar,
     Json,
     Date,
+    Decimal,
+    Char,
     NotNull,
     LParen,
     RParen,
     Comma,
     Semicolon,
-    Text(String),
+    Text(&'a str),
+    Number(u32),
 }
 
-pub struct MatchResult {
-    pub token: LexicalToken,
+pub struct MatchResult<'a> {
+    pub token: LexicalToken<'a>,
     pub start: usize,
     pub end: usize,
 }
 
+/// A value tagged with the byte range of the input it was produced from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub item: T,
+    pub span: (usize, usize),
+}
+
 trait Matcher {
-    fn exec(&self, input: &[u8], position: usize) -> Option<MatchResult>;
+    fn exec<'a>(&self, input: &'a [u8], position: usize) -> Option<MatchResult<'a>>;
 }
 
 struct RegexAndToken {
     regex: Regex,
-    token: LexicalToken,
+    token: LexicalToken<'static>,
 }
 
 struct RegexMatcher {
@@ -40,7 +51,7 @@ struct RegexMatcher {
 }
 
 impl RegexMatcher {
-    fn new(word_and_tokens: Vec<(&str, LexicalToken)>) -> Self {
+    fn new(word_and_tokens: Vec<(&str, LexicalToken<'static>)>) -> Self {
         let regex_and_tokens = word_and_tokens
             .iter()
             .map(|w| RegexAndToken {
@@ -53,7 +64,7 @@ impl RegexMatcher {
 }
 
 impl Matcher for RegexMatcher {
-    fn exec(&self, input: &[u8], position: usize) -> Option<MatchResult> {
+    fn exec<'a>(&self, input: &'a [u8], position: usize) -> Option<MatchResult<'a>> {
         let target = from_utf8(&input[position..]).ok()?;
         for regex_and_token in self.regex_and_tokens.iter() {
             if let Some(m) = regex_and_token.regex.find(target) {
@@ -70,7 +81,7 @@ impl Matcher for RegexMatcher {
 
 struct SymbolAndToken {
     symbol: u8,
-    token: LexicalToken,
+    token: LexicalToken<'static>,
 }
 
 struct SymbolMatcher {
@@ -78,7 +89,7 @@ struct SymbolMatcher {
 }
 
 impl Matcher for SymbolMatcher {
-    fn exec(&self, input: &[u8], position: usize) -> Option<MatchResult> {
+    fn exec<'a>(&self, input: &'a [u8], position: usize) -> Option<MatchResult<'a>> {
         for symbol_and_token in self.symbols.iter() {
             if input[position] == symbol_and_token.symbol {
                 return Some(MatchResult {
@@ -92,6 +103,26 @@ impl Matcher for SymbolMatcher {
     }
 }
 
+struct NumberMatcher {}
+
+impl Matcher for NumberMatcher {
+    fn exec<'a>(&self, input: &'a [u8], position: usize) -> Option<MatchResult<'a>> {
+        let mut new_position = position;
+        while new_position < input.len() && input[new_position].is_ascii_digit() {
+            new_position += 1;
+        }
+        if position != new_position {
+            let text = from_utf8(&input[position..new_position]).ok()?;
+            return Some(MatchResult {
+                token: LexicalToken::Number(text.parse().ok()?),
+                start: position,
+                end: new_position - 1,
+            });
+        }
+        None
+    }
+}
+
 struct TextMatcher {}
 
 impl TextMatcher {
@@ -101,16 +132,16 @@ impl TextMatcher {
 }
 
 impl Matcher for TextMatcher {
-    fn exec(&self, input: &[u8], position: usize) -> Option<MatchResult> {
+    fn exec<'a>(&self, input: &'a [u8], position: usize) -> Option<MatchResult<'a>> {
         let mut new_position = position;
         while new_position < input.len() && self.text_is_valid(input[new_position]) {
             new_position += 1;
         }
         if position != new_position {
             return Some(MatchResult {
-                token: Text(from_utf8(&input[position..new_position]).ok()?.into()),
+                token: Text(from_utf8(&input[position..new_position]).ok()?),
                 start: position,
-                end: new_position,
+                end: new_position - 1,
             });
         }
         None
@@ -119,7 +150,7 @@ impl Matcher for TextMatcher {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LexError {
-    InvalidError(String),
+    InvalidError(String, (usize, usize)),
 }
 
 pub struct Lexer {
@@ -135,6 +166,8 @@ impl Lexer {
                     (r"^(?i)int(eger)?", Int),
                     (r"^(?i)json", Json),
                     (r"^(?i)varchar", Varchar),
+                    (r"^(?i)decimal", Decimal),
+                    (r"^(?i)char", Char),
                     (r"^(?i)date", Date),
                     (r"^(?i)Not Null", NotNull),
                 ])),
@@ -154,18 +187,22 @@ impl Lexer {
                         },
                     ],
                 }),
+                Box::new(NumberMatcher {}),
                 Box::new(TextMatcher {}),
             ],
         }
     }
 
-    fn check(&self, input: &[u8], position: usize) -> Result<MatchResult, LexError> {
+    fn check<'a>(&self, input: &'a [u8], position: usize) -> Result<MatchResult<'a>, LexError> {
         for matcher in &self.matcher {
             if let Some(result) = matcher.exec(input, position) {
                 return Ok(result);
             }
         }
-        Err(InvalidError(format!("Invalid input {}", input[position])))
+        Err(InvalidError(
+            format!("Invalid input {}", input[position]),
+            (position, position),
+        ))
     }
 
     fn skip_space(&self, input: &[u8], position: usize) -> usize {
@@ -176,28 +213,32 @@ impl Lexer {
         new_position
     }
 
-    pub fn run(&self, input: &str) -> Result<Vec<LexicalToken>, LexError> {
+    pub fn run<'a>(&self, input: &'a str) -> Result<Vec<Spanned<LexicalToken<'a>>>, LexError> {
         let input = input.as_bytes();
         let mut pos = 0;
-        let mut tokens: Vec<LexicalToken> = Vec::new();
+        let mut tokens: Vec<Spanned<LexicalToken<'a>>> = Vec::new();
         while pos < input.len() {
             match input[pos] {
                 b' ' | b'\n' | b'\t' => {
                     pos = self.skip_space(input, pos);
                 }
                 b',' => {
-                    tokens.push(Comma);
+                    tokens.push(Spanned {
+                        item: Comma,
+                        span: (pos, pos),
+                    });
                     pos += 1;
                 }
                 _ => {
-                    let _k = input[pos];
                     let result = self.check(input, pos)?;
-                    tokens.push(result.token);
+                    tokens.push(Spanned {
+                        item: result.token,
+                        span: (result.start, result.end),
+                    });
                     pos = result.end + 1;
                 }
             }
         }
-        let _a = input[pos - 2];
         Ok(tokens)
     }
 }
@@ -205,31 +246,70 @@ impl Lexer {
 #[cfg(test)]
 mod tests {
     use crate::lexer::LexicalToken::{
-        Comma, CreateTable, Date, Int, Json, LParen, NotNull, RParen, Semicolon, Text, Varchar,
+        Char, Comma, CreateTable, Date, Decimal, Int, Json, LParen, NotNull, Number, RParen,
+        Semicolon, Text, Varchar,
     };
+    use crate::lexer::Spanned;
     use crate::Lexer;
 
     #[test]
     fn test_run() {
         let lexer = Lexer::new();
         let result = lexer
-            .run("create table not NULL int integer json varchar date ( ) , ; \n \t test_test").unwrap_or_default();
+            .run("create table not NULL int integer json varchar date ( ) , ; \n \t test_test")
+            .unwrap_or_default();
         assert_eq!(
             result,
             vec![
-                CreateTable,
-                NotNull,
-                Int,
-                Int,
-                Json,
-                Varchar,
-                Date,
-                LParen,
-                RParen,
-                Comma,
-                Semicolon,
-                Text("test_test".into())
+                Spanned { item: CreateTable, span: (0, 11) },
+                Spanned { item: NotNull, span: (13, 20) },
+                Spanned { item: Int, span: (22, 24) },
+                Spanned { item: Int, span: (26, 32) },
+                Spanned { item: Json, span: (34, 37) },
+                Spanned { item: Varchar, span: (39, 45) },
+                Spanned { item: Date, span: (47, 50) },
+                Spanned { item: LParen, span: (52, 52) },
+                Spanned { item: RParen, span: (54, 54) },
+                Spanned { item: Comma, span: (56, 56) },
+                Spanned { item: Semicolon, span: (58, 58) },
+                Spanned { item: Text("test_test"), span: (64, 72) },
             ]
         );
     }
+
+    #[test]
+    fn test_run_parameterized_types() {
+        let lexer = Lexer::new();
+        let result = lexer
+            .run("varchar(255) decimal(10,2) char")
+            .unwrap_or_default();
+        assert_eq!(
+            result,
+            vec![
+                Spanned { item: Varchar, span: (0, 6) },
+                Spanned { item: LParen, span: (7, 7) },
+                Spanned { item: Number(255), span: (8, 10) },
+                Spanned { item: RParen, span: (11, 11) },
+                Spanned { item: Decimal, span: (13, 19) },
+                Spanned { item: LParen, span: (20, 20) },
+                Spanned { item: Number(10), span: (21, 22) },
+                Spanned { item: Comma, span: (23, 23) },
+                Spanned { item: Number(2), span: (24, 24) },
+                Spanned { item: RParen, span: (25, 25) },
+                Spanned { item: Char, span: (27, 30) },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_borrows_identifiers_from_the_input_buffer() {
+        let lexer = Lexer::new();
+        let source = "create table t ( a_column int ) ;".to_string();
+        let result = lexer.run(&source).unwrap();
+        let Text(name) = result[3].item else {
+            panic!("expected a borrowed Text token");
+        };
+        // The token's text is a slice of `source`, not a fresh allocation.
+        assert_eq!(name.as_ptr(), source[17..].as_ptr());
+    }
 }
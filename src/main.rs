@@ -1,14 +1,17 @@
+mod codegen;
+mod diagnostics;
 mod interpreter;
 mod lexer;
 mod parser;
 
 use crate::interpreter::Interpreter;
-use crate::lexer::Lexer;
-use crate::parser::Parser;
+use crate::lexer::{LexError, Lexer};
+use crate::parser::{ParseError, Parser, StatementError};
 
 use std::env;
 use std::fs::File;
-use std::io::{Read};
+use std::io::Read;
+use std::process::exit;
 
 /*
 
@@ -28,21 +31,69 @@ fn main() {
     let lexer = Lexer::new();
     let tokens = match lexer.run(&contents) {
         Ok(tokens) => tokens,
-        Err(error) => {
-            panic!("Lexical error: {:?}", error)
+        Err(LexError::InvalidError(message, span)) => {
+            eprintln!(
+                "{}",
+                diagnostics::render(&contents, span, &format!("Lexical error: {message}"))
+            );
+            exit(1);
         }
     };
 
     let parser = Parser::new();
-    let ast = match parser.run(tokens) {
-        Ok(ast) => ast,
-        Err(error) => {
-            panic!("Parse error: {:?}", error)
+    let (statements, errors) = match parser.run(tokens) {
+        Ok(result) => result,
+        Err(StatementError {
+            statement_index,
+            span,
+            source: ParseError::UnexpectedToken(token, _),
+        }) => {
+            eprintln!(
+                "{}",
+                diagnostics::render(
+                    &contents,
+                    span,
+                    &format!(
+                        "Parse error in statement {statement_index}: unexpected token {token:?}"
+                    )
+                )
+            );
+            exit(1);
+        }
+        Err(StatementError {
+            statement_index,
+            source: ParseError::Eof,
+            ..
+        }) => {
+            eprintln!("Parse error in statement {statement_index}: unexpected end of input");
+            exit(1);
         }
     };
 
+    for error in &errors {
+        match error {
+            ParseError::UnexpectedToken(token, span) => {
+                eprintln!(
+                    "{}",
+                    diagnostics::render(
+                        &contents,
+                        *span,
+                        &format!("Parse error: unexpected token {token:?}")
+                    )
+                );
+            }
+            ParseError::Eof => eprintln!("Parse error: unexpected end of input"),
+        }
+    }
+
+    if !errors.is_empty() {
+        exit(1);
+    }
+
     let interpreter = Interpreter {};
-    let result = interpreter.run(ast);
+    let tables = interpreter.run(statements);
 
-    println!("result:\n{:?}", result);
+    for table in &tables {
+        print!("{}", codegen::emit(table));
+    }
 }